@@ -0,0 +1,122 @@
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+// Returned by check_role when the address holds no roles at all
+const NO_ROLE: Symbol = symbol_short!("NO_ROLE");
+// Role required to call remove_role on someone else's behalf
+const REMOVER: Symbol = symbol_short!("REMOVER");
+
+pub trait AuthTrait {
+    // Backward-compatible single-role API: replaces whatever roles `address`
+    // held with just `role`.
+    fn set_role(env: Env, role: Symbol, address: Address);
+    // Backward-compatible single-role API: the first role `address` holds,
+    // or NO_ROLE if it holds none.
+    fn check_role(env: Env, address: Address) -> Symbol;
+    // Adds `role` to `address`'s role set without disturbing any other role
+    // it already holds.
+    fn grant_role(env: Env, role: Symbol, address: Address);
+    // Removes `role` from `address`'s role set, leaving any other roles
+    // untouched.
+    fn revoke_role(env: Env, role: Symbol, address: Address);
+    // Membership check over `address`'s role set.
+    fn has_role(env: Env, address: Address, role: Symbol) -> bool;
+    // Strips every role `address` holds. `caller` must authorize the call
+    // and hold REMOVER, letting a controlling contract demote an address it
+    // granted a role to without going through the admin.
+    fn remove_role(env: Env, address: Address, caller: Address);
+}
+
+// Key for storing an address's role set
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,           // Address
+    Roles(Address),  // Vec<Symbol>
+}
+
+// Main contract
+#[contract]
+pub struct AuthContract;
+
+pub trait ConstructorTrait {
+    fn __constructor(env: Env, admin: Address);
+}
+
+#[contractimpl]
+impl ConstructorTrait for AuthContract {
+    fn __constructor(env: Env, admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+}
+
+#[contractimpl]
+impl AuthTrait for AuthContract {
+    fn set_role(env: Env, role: Symbol, address: Address) {
+        require_admin(&env);
+
+        let mut roles = Vec::new(&env);
+        roles.push_back(role);
+        set_roles(&env, &address, &roles);
+    }
+
+    fn check_role(env: Env, address: Address) -> Symbol {
+        get_roles(&env, &address).get(0).unwrap_or(NO_ROLE)
+    }
+
+    fn grant_role(env: Env, role: Symbol, address: Address) {
+        require_admin(&env);
+
+        let mut roles = get_roles(&env, &address);
+        if !roles.iter().any(|r| r == role) {
+            roles.push_back(role);
+        }
+        set_roles(&env, &address, &roles);
+    }
+
+    fn revoke_role(env: Env, role: Symbol, address: Address) {
+        require_admin(&env);
+
+        let roles = get_roles(&env, &address);
+        let mut remaining = Vec::new(&env);
+        for r in roles.iter() {
+            if r != role {
+                remaining.push_back(r);
+            }
+        }
+        set_roles(&env, &address, &remaining);
+    }
+
+    fn has_role(env: Env, address: Address, role: Symbol) -> bool {
+        get_roles(&env, &address).iter().any(|r| r == role)
+    }
+
+    fn remove_role(env: Env, address: Address, caller: Address) {
+        caller.require_auth();
+        if !get_roles(&env, &caller).iter().any(|r| r == REMOVER) {
+            panic!("caller does not hold REMOVER");
+        }
+        set_roles(&env, &address, &Vec::new(&env));
+    }
+}
+
+fn require_admin(env: &Env) {
+    let admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .expect("Contract has not been initialized!");
+    admin.require_auth();
+}
+
+fn get_roles(env: &Env, address: &Address) -> Vec<Symbol> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Roles(address.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_roles(env: &Env, address: &Address, roles: &Vec<Symbol>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Roles(address.clone()), roles);
+}
@@ -0,0 +1,89 @@
+#![cfg(test)]
+
+use crate::auth_contract::{AuthContract, AuthContractArgs, AuthContractClient};
+use soroban_sdk::{symbol_short, testutils::Address, Env};
+
+#[test]
+fn test_set_role_and_check_role() {
+    let env = Env::default();
+    let admin = <soroban_sdk::Address as Address>::generate(&env);
+    env.mock_all_auths();
+
+    let contract_id = env.register(AuthContract, AuthContractArgs::__constructor(&admin));
+    let client = AuthContractClient::new(&env, &contract_id);
+
+    let user = <soroban_sdk::Address as Address>::generate(&env);
+    assert_eq!(symbol_short!("NO_ROLE"), client.check_role(&user));
+
+    client.set_role(&symbol_short!("COUNTER"), &user);
+    assert_eq!(symbol_short!("COUNTER"), client.check_role(&user));
+
+    // set_role replaces the role set rather than adding to it.
+    client.set_role(&symbol_short!("REMOVER"), &user);
+    assert_eq!(symbol_short!("REMOVER"), client.check_role(&user));
+    assert!(!client.has_role(&user, &symbol_short!("COUNTER")));
+}
+
+#[test]
+fn test_grant_and_revoke_role_are_additive() {
+    let env = Env::default();
+    let admin = <soroban_sdk::Address as Address>::generate(&env);
+    env.mock_all_auths();
+
+    let contract_id = env.register(AuthContract, AuthContractArgs::__constructor(&admin));
+    let client = AuthContractClient::new(&env, &contract_id);
+
+    let user = <soroban_sdk::Address as Address>::generate(&env);
+    client.grant_role(&symbol_short!("COUNTER"), &user);
+    client.grant_role(&symbol_short!("REMOVER"), &user);
+
+    assert!(client.has_role(&user, &symbol_short!("COUNTER")));
+    assert!(client.has_role(&user, &symbol_short!("REMOVER")));
+
+    // Granting a role the address already holds is a no-op, not a duplicate.
+    client.grant_role(&symbol_short!("COUNTER"), &user);
+    assert!(client.has_role(&user, &symbol_short!("COUNTER")));
+
+    client.revoke_role(&symbol_short!("COUNTER"), &user);
+    assert!(!client.has_role(&user, &symbol_short!("COUNTER")));
+    assert!(client.has_role(&user, &symbol_short!("REMOVER")));
+}
+
+#[test]
+fn test_remove_role_clears_every_role() {
+    let env = Env::default();
+    let admin = <soroban_sdk::Address as Address>::generate(&env);
+    env.mock_all_auths();
+
+    let contract_id = env.register(AuthContract, AuthContractArgs::__constructor(&admin));
+    let client = AuthContractClient::new(&env, &contract_id);
+
+    let user = <soroban_sdk::Address as Address>::generate(&env);
+    let caller = <soroban_sdk::Address as Address>::generate(&env);
+    client.grant_role(&symbol_short!("COUNTER"), &user);
+    client.grant_role(&symbol_short!("REMOVER"), &user);
+    client.grant_role(&symbol_short!("REMOVER"), &caller);
+
+    client.remove_role(&user, &caller);
+
+    assert!(!client.has_role(&user, &symbol_short!("COUNTER")));
+    assert!(!client.has_role(&user, &symbol_short!("REMOVER")));
+    assert_eq!(symbol_short!("NO_ROLE"), client.check_role(&user));
+}
+
+#[test]
+#[should_panic(expected = "caller does not hold REMOVER")]
+fn test_remove_role_rejects_caller_without_remover() {
+    let env = Env::default();
+    let admin = <soroban_sdk::Address as Address>::generate(&env);
+    env.mock_all_auths();
+
+    let contract_id = env.register(AuthContract, AuthContractArgs::__constructor(&admin));
+    let client = AuthContractClient::new(&env, &contract_id);
+
+    let user = <soroban_sdk::Address as Address>::generate(&env);
+    let caller = <soroban_sdk::Address as Address>::generate(&env);
+    client.grant_role(&symbol_short!("COUNTER"), &user);
+
+    client.remove_role(&user, &caller);
+}
@@ -1,4 +1,5 @@
-use soroban_sdk::{contract, contractimpl, contracttype, Env};
+use crate::storage::{CounterStorage, Durability, InstanceStorage, PersistentStorage, TemporaryStorage};
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Env};
 
 // Interface for the counter contract - all functions need the Stellar environment
 pub trait CounterTrait {
@@ -14,42 +15,98 @@ pub trait CounterTrait {
 #[derive(Clone)]
 #[contracttype]
 pub enum DataKey {
-    Count, // Stores a u64 value
+    Count,       // Stores a u64 value
+    Durability,  // Stores which backend holds DataKey::Count
 }
 
 // Main contract
 #[contract]
 pub struct CounterContract;
 
+pub trait ConstructorTrait {
+    fn __constructor(env: Env, initial: u64, durability: Durability);
+}
+
+#[contractimpl]
+impl ConstructorTrait for CounterContract {
+    fn __constructor(env: Env, initial: u64, durability: Durability) {
+        // The durability flag itself always lives in instance storage - it's
+        // small, constant after construction, and needs to survive as long
+        // as the contract does regardless of where the count lives.
+        env.storage()
+            .instance()
+            .set(&DataKey::Durability, &durability);
+        set_count(&env, &durability, &initial);
+    }
+}
+
 // Contract implementation with overflow protection using saturating math
 #[contractimpl]
 impl CounterTrait for CounterContract {
     fn count(env: Env) -> u64 {
-        env.storage()
-            .instance()
-            .get(&DataKey::Count)
-            .unwrap_or(0_u64)
+        let durability = get_durability(&env);
+        let count = get_count(&env, &durability);
+        extend_ttl(&env, &durability);
+        count
     }
 
     fn add(env: Env, amount: u64) -> u64 {
-        let count = env
-            .storage()
-            .instance()
-            .get(&DataKey::Count)
-            .unwrap_or(0_u64);
+        let durability = get_durability(&env);
+        let count = get_count(&env, &durability);
         let new_count = count.saturating_add(amount); // Cap at max to prevent overflow
-        env.storage().instance().set(&DataKey::Count, &new_count);
+        set_count(&env, &durability, &new_count);
+        extend_ttl(&env, &durability);
+        // No caller to report here - unlike CrossCounterContract's 3-tuple
+        // (caller, amount, new_count), this contract's add/subtract take no
+        // caller argument, so the payload is just (amount, new_count).
+        // Indexers watching both contracts should not assume a uniform
+        // event shape.
+        env.events()
+            .publish((symbol_short!("counter"), symbol_short!("add")), (amount, new_count));
         new_count
     }
 
     fn subtract(env: Env, amount: u64) -> u64 {
-        let count = env
-            .storage()
-            .instance()
-            .get(&DataKey::Count)
-            .unwrap_or(0_u64);
+        let durability = get_durability(&env);
+        let count = get_count(&env, &durability);
         let new_count = count.saturating_sub(amount); // Cap at min to prevent underflow
-        env.storage().instance().set(&DataKey::Count, &new_count);
+        set_count(&env, &durability, &new_count);
+        extend_ttl(&env, &durability);
+        // See the note on add() above: (amount, new_count), no caller.
+        env.events()
+            .publish((symbol_short!("counter"), symbol_short!("sub")), (amount, new_count));
         new_count
     }
 }
+
+fn get_durability(env: &Env) -> Durability {
+    env.storage()
+        .instance()
+        .get(&DataKey::Durability)
+        .unwrap_or(Durability::Instance)
+}
+
+fn get_count(env: &Env, durability: &Durability) -> u64 {
+    let count = match durability {
+        Durability::Instance => InstanceStorage::get_count(env),
+        Durability::Persistent => PersistentStorage::get_count(env),
+        Durability::Temporary => TemporaryStorage::get_count(env),
+    };
+    count.unwrap_or(0_u64)
+}
+
+fn set_count(env: &Env, durability: &Durability, value: &u64) {
+    match durability {
+        Durability::Instance => InstanceStorage::set_count(env, value),
+        Durability::Persistent => PersistentStorage::set_count(env, value),
+        Durability::Temporary => TemporaryStorage::set_count(env, value),
+    }
+}
+
+fn extend_ttl(env: &Env, durability: &Durability) {
+    match durability {
+        Durability::Instance => InstanceStorage::extend_ttl(env),
+        Durability::Persistent => PersistentStorage::extend_ttl(env),
+        Durability::Temporary => TemporaryStorage::extend_ttl(env),
+    }
+}
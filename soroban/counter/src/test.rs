@@ -1,13 +1,17 @@
 #![cfg(test)]
 
-use crate::contract::{CounterContract, CounterContractClient};
+use crate::contract::{CounterContract, CounterContractArgs, CounterContractClient, DataKey};
+use crate::storage::Durability;
 
-use soroban_sdk::Env;
+use soroban_sdk::{symbol_short, testutils::Events, testutils::Ledger, vec, Env, IntoVal};
 
 #[test]
 fn test() {
     let env = Env::default();
-    let contract_id = env.register_contract(None, CounterContract);
+    let contract_id = env.register(
+        CounterContract,
+        CounterContractArgs::__constructor(&0_u64, &Durability::Instance),
+    );
     let client = CounterContractClient::new(&env, &contract_id);
 
     // Test initial count
@@ -36,3 +40,105 @@ fn test() {
     assert_eq!(u64::MAX, client.add(&1_u64)); // Add 1
     assert_eq!(u64::MAX, client.count()); // Count should be u64::MAX
 }
+
+#[test]
+fn test_seeded_initial_value() {
+    let env = Env::default();
+    let contract_id = env.register(
+        CounterContract,
+        CounterContractArgs::__constructor(&42_u64, &Durability::Instance),
+    );
+    let client = CounterContractClient::new(&env, &contract_id);
+
+    // Count should reflect the seeded value on first access
+    assert_eq!(42, client.count());
+
+    assert_eq!(45, client.add(&3_u64)); // Add 3 = 45
+    assert_eq!(40, client.subtract(&5_u64)); // Subtract 5 = 40
+}
+
+#[test]
+fn test_events() {
+    let env = Env::default();
+    let contract_id = env.register(
+        CounterContract,
+        CounterContractArgs::__constructor(&0_u64, &Durability::Instance),
+    );
+    let client = CounterContractClient::new(&env, &contract_id);
+
+    client.add(&5_u64);
+    client.subtract(&2_u64);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_id.clone(),
+                (symbol_short!("counter"), symbol_short!("add")).into_val(&env),
+                (5_u64, 5_u64).into_val(&env),
+            ),
+            (
+                contract_id.clone(),
+                (symbol_short!("counter"), symbol_short!("sub")).into_val(&env),
+                (2_u64, 3_u64).into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_durability_modes_extend_ttl() {
+    for durability in [
+        Durability::Instance,
+        Durability::Persistent,
+        Durability::Temporary,
+    ] {
+        let env = Env::default();
+        let contract_id = env.register(
+            CounterContract,
+            CounterContractArgs::__constructor(&0_u64, &durability),
+        );
+        let client = CounterContractClient::new(&env, &contract_id);
+
+        let ttl = || {
+            env.as_contract(&contract_id, || match durability {
+                Durability::Instance => env.storage().instance().get_ttl(),
+                Durability::Persistent => env.storage().persistent().get_ttl(&DataKey::Count),
+                Durability::Temporary => env.storage().temporary().get_ttl(&DataKey::Count),
+            })
+        };
+
+        // Let the TTL decay to 1 ledger so only add() can have bumped it.
+        let ttl_after_construct = ttl();
+        env.ledger()
+            .with_mut(|li| li.sequence_number += ttl_after_construct - 1);
+
+        assert_eq!(1, client.add(&1_u64));
+
+        assert!(ttl() > 1);
+    }
+}
+
+#[test]
+fn test_count_extends_ttl_on_read_only_access() {
+    let env = Env::default();
+    let contract_id = env.register(
+        CounterContract,
+        CounterContractArgs::__constructor(&0_u64, &Durability::Persistent),
+    );
+    let client = CounterContractClient::new(&env, &contract_id);
+
+    let ttl_after_construct =
+        env.as_contract(&contract_id, || env.storage().persistent().get_ttl(&DataKey::Count));
+
+    // Let the TTL decay, then read the count without ever calling add/subtract.
+    env.ledger()
+        .with_mut(|li| li.sequence_number += ttl_after_construct - 1);
+    client.count();
+
+    let ttl_after_read =
+        env.as_contract(&contract_id, || env.storage().persistent().get_ttl(&DataKey::Count));
+
+    assert!(ttl_after_read > 1);
+}
@@ -1,9 +1,23 @@
+use crate::storage::{CounterStorage, Durability, InstanceStorage, PersistentStorage, TemporaryStorage};
 use authorization::auth_contract::{AuthContract, AuthContractClient};
-use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, Symbol};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, Symbol,
+};
+
+// Errors surfaced to callers instead of opaque host traps
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    NotInitialized = 1,
+    Unauthorized = 2,
+    Overflow = 3,
+}
+
 pub trait ControlledCounterTrait {
     fn count(env: Env) -> u64;
-    fn add(env: Env, counter: Address, amount: u64) -> u64;
-    fn subtract(env: Env, counter: Address, amount: u64) -> u64;
+    fn add(env: Env, counter: Address, amount: u64) -> Result<u64, Error>;
+    fn subtract(env: Env, counter: Address, amount: u64) -> Result<u64, Error>;
 }
 
 // Key for storing the count value
@@ -12,6 +26,7 @@ pub trait ControlledCounterTrait {
 pub enum DataKey {
     Count,         // u64
     AccessControl, // Address
+    Durability,    // Which backend holds DataKey::Count
 }
 
 // Main contract
@@ -19,72 +34,140 @@ pub enum DataKey {
 pub struct CrossCounterContract;
 
 pub trait ConstructorTrait {
-    fn __constructor(e: Env, controller: Address);
+    fn __constructor(e: Env, controller: Address, durability: Durability);
 }
 
 #[contractimpl]
 impl ConstructorTrait for CrossCounterContract {
-    fn __constructor(e: Env, controller: Address) {
+    fn __constructor(e: Env, controller: Address, durability: Durability) {
         e.storage()
             .instance()
             .set(&DataKey::AccessControl, &controller);
+        e.storage()
+            .instance()
+            .set(&DataKey::Durability, &durability);
+        // Seed DataKey::Count so extend_ttl always has an existing entry to
+        // bump, even under persistent/temporary storage where the key must
+        // exist before its TTL can be extended.
+        set_count(&e, &durability, &0_u64);
     }
 }
 
 #[contractimpl]
 impl ControlledCounterTrait for CrossCounterContract {
     fn count(env: Env) -> u64 {
-        env.storage().instance().get(&DataKey::Count).unwrap_or(0)
+        let durability = get_durability(&env);
+        let count = get_count(&env, &durability);
+        extend_ttl(&env, &durability);
+        count
     }
 
-    fn add(env: Env, counter: Address, amount: u64) -> u64 {
-        require_counter(&env, counter.clone());
-
-        let count: u64 = env.storage().instance().get(&DataKey::Count).unwrap_or(0);
-
-        let new_count = if count.saturating_add(amount) > 100 {
-            remove_counter_role(&env, counter);
-            0
+    fn add(env: Env, counter: Address, amount: u64) -> Result<u64, Error> {
+        require_counter(&env, counter.clone())?;
+
+        let durability = get_durability(&env);
+        let count = get_count(&env, &durability);
+        let summed = count.checked_add(amount).ok_or(Error::Overflow)?;
+
+        let new_count = if summed > 100 {
+            remove_counter_role(&env, counter.clone())?;
+            set_count(&env, &durability, &0_u64);
+            extend_ttl(&env, &durability);
+            env.events().publish(
+                (symbol_short!("counter"), symbol_short!("reset")),
+                (counter, amount, 0_u64),
+            );
+            return Ok(0);
         } else {
-            count.saturating_add(amount)
+            summed
         };
 
-        env.storage().instance().set(&DataKey::Count, &new_count);
-        new_count
+        set_count(&env, &durability, &new_count);
+        extend_ttl(&env, &durability);
+        env.events().publish(
+            (symbol_short!("counter"), symbol_short!("add")),
+            (counter, amount, new_count),
+        );
+        Ok(new_count)
     }
 
-    fn subtract(env: Env, counter: Address, amount: u64) -> u64 {
+    fn subtract(env: Env, counter: Address, amount: u64) -> Result<u64, Error> {
         counter.require_auth();
 
-        let count: u64 = env.storage().instance().get(&DataKey::Count).unwrap_or(0);
+        let durability = get_durability(&env);
+        let count = get_count(&env, &durability);
+        let new_count = count.saturating_sub(amount);
+
+        set_count(&env, &durability, &new_count);
+        extend_ttl(&env, &durability);
+        env.events().publish(
+            (symbol_short!("counter"), symbol_short!("sub")),
+            (counter, amount, new_count),
+        );
+        Ok(new_count)
+    }
+}
 
-        env.storage()
-            .instance()
-            .set(&DataKey::Count, &count.saturating_sub(amount));
-        count.saturating_sub(amount)
+fn get_durability(env: &Env) -> Durability {
+    env.storage()
+        .instance()
+        .get(&DataKey::Durability)
+        .unwrap_or(Durability::Instance)
+}
+
+fn get_count(env: &Env, durability: &Durability) -> u64 {
+    let count = match durability {
+        Durability::Instance => InstanceStorage::get_count(env),
+        Durability::Persistent => PersistentStorage::get_count(env),
+        Durability::Temporary => TemporaryStorage::get_count(env),
+    };
+    count.unwrap_or(0_u64)
+}
+
+fn set_count(env: &Env, durability: &Durability, value: &u64) {
+    match durability {
+        Durability::Instance => InstanceStorage::set_count(env, value),
+        Durability::Persistent => PersistentStorage::set_count(env, value),
+        Durability::Temporary => TemporaryStorage::set_count(env, value),
+    }
+}
+
+fn extend_ttl(env: &Env, durability: &Durability) {
+    match durability {
+        Durability::Instance => InstanceStorage::extend_ttl(env),
+        Durability::Persistent => PersistentStorage::extend_ttl(env),
+        Durability::Temporary => TemporaryStorage::extend_ttl(env),
     }
 }
 
-fn require_counter(env: &Env, counter: Address) {
-    let controller = env
+fn require_counter(env: &Env, counter: Address) -> Result<(), Error> {
+    let controller: Address = env
         .storage()
         .instance()
         .get(&DataKey::AccessControl)
-        .unwrap_or_else(|| panic!("Contract has not been initialized!"));
+        .ok_or(Error::NotInitialized)?;
 
+    // `has_role` checks membership in the address's role set, so an address
+    // holding COUNTER alongside other roles (e.g. REMOVER) still passes.
     let controller_client = AuthContractClient::new(&env, &controller);
-    controller_client.verify_auth(&counter, &symbol_short!("COUNTER"));
+    if !controller_client.has_role(&counter, &symbol_short!("COUNTER")) {
+        return Err(Error::Unauthorized);
+    }
 
     counter.require_auth();
+
+    Ok(())
 }
 
-fn remove_counter_role(env: &Env, counter: Address) {
-    let controller = env
+fn remove_counter_role(env: &Env, counter: Address) -> Result<(), Error> {
+    let controller: Address = env
         .storage()
         .instance()
         .get(&DataKey::AccessControl)
-        .unwrap_or_else(|| panic!("Contract has not been initialized!"));
+        .ok_or(Error::NotInitialized)?;
 
     let controller_client = AuthContractClient::new(&env, &controller);
     controller_client.remove_role(&counter, &env.current_contract_address());
+
+    Ok(())
 }
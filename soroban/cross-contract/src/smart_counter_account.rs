@@ -0,0 +1,90 @@
+use soroban_sdk::{
+    auth::{Context, ContractContext, CustomAccountInterface},
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, BytesN, Env,
+    IntoVal, Vec,
+};
+
+// Errors surfaced from __check_auth instead of opaque host traps
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AmountExceedsThreshold = 1,
+}
+
+// Key for the per-account spending policy
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Counter,   // Address - the CrossCounterContract this account is scoped to
+    Threshold, // u64 - the max amount allowed in a single `add`/`subtract` on Counter
+}
+
+// A smart-wallet style account contract: any address can be registered as a
+// `counter` on `CrossCounterContract` as long as it is happy to delegate its
+// `require_auth` checks to this contract's `__check_auth`.
+#[contract]
+pub struct SmartCounterAccount;
+
+pub trait ConstructorTrait {
+    fn __constructor(env: Env, counter: Address, threshold: u64);
+}
+
+#[contractimpl]
+impl ConstructorTrait for SmartCounterAccount {
+    fn __constructor(env: Env, counter: Address, threshold: u64) {
+        env.storage().instance().set(&DataKey::Counter, &counter);
+        env.storage().instance().set(&DataKey::Threshold, &threshold);
+    }
+}
+
+#[contractimpl]
+impl CustomAccountInterface for SmartCounterAccount {
+    type Error = Error;
+    type Signature = ();
+
+    // Policy: only `add`/`subtract` invocations targeting our own counter
+    // contract are considered at all; everything else in the auth batch is
+    // left alone. Of those, any `amount` argument exceeding the stored
+    // threshold is rejected before the host even considers the signature
+    // valid.
+    fn __check_auth(
+        env: Env,
+        _signature_payload: BytesN<32>,
+        _signature: (),
+        auth_contexts: Vec<Context>,
+    ) -> Result<(), Error> {
+        let counter: Address = env.storage().instance().get(&DataKey::Counter).unwrap();
+        let threshold: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::Threshold)
+            .unwrap_or(u64::MAX);
+
+        for context in auth_contexts.iter() {
+            if let Context::Contract(ContractContext {
+                contract,
+                fn_name,
+                args,
+            }) = context
+            {
+                let is_add = fn_name == symbol_short!("add");
+                let is_subtract = fn_name == symbol_short!("subtract");
+                if contract != counter || !(is_add || is_subtract) {
+                    continue;
+                }
+
+                // `add`/`subtract(counter: Address, amount: u64)` - amount is
+                // the second argument
+                if let Some(amount) = args.get(1) {
+                    let amount: u64 = amount.into_val(&env);
+                    if amount > threshold {
+                        return Err(Error::AmountExceedsThreshold);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use crate::cross_contract::DataKey;
+use soroban_sdk::{contracttype, Env};
+
+// This file is intentionally near-identical to counter/src/storage.rs: each
+// contract is its own deployable crate with no shared library crate between
+// them, so there's nowhere to fold the common Durability/CounterStorage
+// plumbing without adding one. Keep the two in sync by hand until a shared
+// crate exists.
+
+// Durability chosen for the count at construction time, decoupling how long
+// the value lives from where it's stored.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[contracttype]
+pub enum Durability {
+    Instance,
+    Persistent,
+    Temporary,
+}
+
+// TTL threshold/extend-to pairs (in ledgers) per durability kind
+const INSTANCE_TTL_THRESHOLD: u32 = 10;
+const INSTANCE_TTL_EXTEND_TO: u32 = 50;
+const PERSISTENT_TTL_THRESHOLD: u32 = 100;
+const PERSISTENT_TTL_EXTEND_TO: u32 = 1_000;
+const TEMPORARY_TTL_THRESHOLD: u32 = 10;
+const TEMPORARY_TTL_EXTEND_TO: u32 = 50;
+
+// Abstracts reading/writing DataKey::Count and extending its TTL away from
+// which storage backend actually holds it.
+pub trait CounterStorage {
+    fn get_count(env: &Env) -> Option<u64>;
+    fn set_count(env: &Env, value: &u64);
+    fn extend_ttl(env: &Env);
+}
+
+pub struct InstanceStorage;
+
+impl CounterStorage for InstanceStorage {
+    fn get_count(env: &Env) -> Option<u64> {
+        env.storage().instance().get(&DataKey::Count)
+    }
+
+    fn set_count(env: &Env, value: &u64) {
+        env.storage().instance().set(&DataKey::Count, value);
+    }
+
+    fn extend_ttl(env: &Env) {
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_TTL_THRESHOLD, INSTANCE_TTL_EXTEND_TO);
+    }
+}
+
+pub struct PersistentStorage;
+
+impl CounterStorage for PersistentStorage {
+    fn get_count(env: &Env) -> Option<u64> {
+        env.storage().persistent().get(&DataKey::Count)
+    }
+
+    fn set_count(env: &Env, value: &u64) {
+        env.storage().persistent().set(&DataKey::Count, value);
+    }
+
+    fn extend_ttl(env: &Env) {
+        env.storage().persistent().extend_ttl(
+            &DataKey::Count,
+            PERSISTENT_TTL_THRESHOLD,
+            PERSISTENT_TTL_EXTEND_TO,
+        );
+    }
+}
+
+pub struct TemporaryStorage;
+
+impl CounterStorage for TemporaryStorage {
+    fn get_count(env: &Env) -> Option<u64> {
+        env.storage().temporary().get(&DataKey::Count)
+    }
+
+    fn set_count(env: &Env, value: &u64) {
+        env.storage().temporary().set(&DataKey::Count, value);
+    }
+
+    fn extend_ttl(env: &Env) {
+        env.storage().temporary().extend_ttl(
+            &DataKey::Count,
+            TEMPORARY_TTL_THRESHOLD,
+            TEMPORARY_TTL_EXTEND_TO,
+        );
+    }
+}
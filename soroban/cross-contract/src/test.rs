@@ -1,10 +1,16 @@
 #![cfg(test)]
 
 use crate::cross_contract::{
-    CrossCounterContract, CrossCounterContractArgs, CrossCounterContractClient,
+    CrossCounterContract, CrossCounterContractArgs, CrossCounterContractClient, Error,
 };
+use crate::storage::Durability;
+use crate::smart_counter_account::{SmartCounterAccount, SmartCounterAccountArgs};
 use authorization::auth_contract::{AuthContract, AuthContractArgs, AuthContractClient};
-use soroban_sdk::{symbol_short, testutils::Address, Env};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address, Events, Ledger, MockAuth, MockAuthInvoke},
+    Env, IntoVal,
+};
 
 #[test]
 fn test_successful_operations() {
@@ -17,7 +23,7 @@ fn test_successful_operations() {
 
     let counter_contract_id = env.register(
         CrossCounterContract,
-        CrossCounterContractArgs::__constructor(&auth_contract_id),
+        CrossCounterContractArgs::__constructor(&auth_contract_id, &Durability::Instance),
     );
     let auth_client = AuthContractClient::new(&env, &auth_contract_id);
     let counter_client = CrossCounterContractClient::new(&env, &counter_contract_id);
@@ -50,3 +56,316 @@ fn test_successful_operations() {
 
     assert_eq!(symbol_short!("NO_ROLE"), auth_client.check_role(&user_a));
 }
+
+#[test]
+fn test_events_emitted() {
+    let env = Env::default();
+    let admin = <soroban_sdk::Address as Address>::generate(&env);
+
+    env.mock_all_auths();
+
+    let auth_contract_id = env.register(AuthContract, AuthContractArgs::__constructor(&admin));
+
+    let counter_contract_id = env.register(
+        CrossCounterContract,
+        CrossCounterContractArgs::__constructor(&auth_contract_id, &Durability::Instance),
+    );
+    let auth_client = AuthContractClient::new(&env, &auth_contract_id);
+    let counter_client = CrossCounterContractClient::new(&env, &counter_contract_id);
+
+    auth_client.set_role(&symbol_short!("REMOVER"), &counter_contract_id);
+    let user_a = <soroban_sdk::Address as Address>::generate(&env);
+    auth_client.set_role(&symbol_short!("COUNTER"), &user_a);
+
+    counter_client.add(&user_a, &1_u64);
+    counter_client.subtract(&user_a, &1_u64);
+    counter_client.add(&user_a, &101_u64); // Crosses 100, triggers demotion
+
+    // Only look at events published by the counter contract itself.
+    let counter_events: std::vec::Vec<_> = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(id, _, _)| *id == counter_contract_id)
+        .collect();
+
+    assert_eq!(
+        counter_events[0],
+        (
+            counter_contract_id.clone(),
+            (symbol_short!("counter"), symbol_short!("add")).into_val(&env),
+            (user_a.clone(), 1_u64, 1_u64).into_val(&env),
+        )
+    );
+    assert_eq!(
+        counter_events[1],
+        (
+            counter_contract_id.clone(),
+            (symbol_short!("counter"), symbol_short!("sub")).into_val(&env),
+            (user_a.clone(), 1_u64, 0_u64).into_val(&env),
+        )
+    );
+    assert_eq!(
+        counter_events[2],
+        (
+            counter_contract_id.clone(),
+            (symbol_short!("counter"), symbol_short!("reset")).into_val(&env),
+            (user_a.clone(), 101_u64, 0_u64).into_val(&env),
+        )
+    );
+    // The demotion branch only emits "reset" - no "add" event, since no
+    // addition actually took effect.
+    assert_eq!(counter_events.len(), 3);
+}
+
+#[test]
+fn test_try_add_not_initialized() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // Register the contract without running its constructor, so AccessControl
+    // was never seeded - simulates a client calling into an unconstructed instance.
+    let counter_contract_id = env.register_contract(None, CrossCounterContract);
+    let counter_client = CrossCounterContractClient::new(&env, &counter_contract_id);
+
+    let user_a = <soroban_sdk::Address as Address>::generate(&env);
+
+    assert_eq!(
+        counter_client.try_add(&user_a, &1_u64),
+        Ok(Err(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_try_add_overflow() {
+    let env = Env::default();
+    let admin = <soroban_sdk::Address as Address>::generate(&env);
+
+    env.mock_all_auths();
+
+    let auth_contract_id = env.register(AuthContract, AuthContractArgs::__constructor(&admin));
+    let counter_contract_id = env.register(
+        CrossCounterContract,
+        CrossCounterContractArgs::__constructor(&auth_contract_id, &Durability::Instance),
+    );
+    let auth_client = AuthContractClient::new(&env, &auth_contract_id);
+    let counter_client = CrossCounterContractClient::new(&env, &counter_contract_id);
+
+    let user_a = <soroban_sdk::Address as Address>::generate(&env);
+    auth_client.grant_role(&symbol_short!("COUNTER"), &user_a);
+
+    // Demotion caps count at <= 100, so checked_add can only overflow once
+    // count is non-zero and amount pushes it past u64::MAX.
+    counter_client.add(&user_a, &1_u64);
+
+    assert_eq!(
+        counter_client.try_add(&user_a, &u64::MAX),
+        Ok(Err(Error::Overflow))
+    );
+}
+
+#[test]
+fn test_smart_account_rejects_over_threshold_add_end_to_end() {
+    let env = Env::default();
+    let admin = <soroban_sdk::Address as Address>::generate(&env);
+
+    // Admin-gated setup (granting roles) is mocked; the account's own
+    // require_auth below is mocked separately so it still runs the real
+    // __check_auth logic.
+    env.mock_all_auths();
+
+    let auth_contract_id = env.register(AuthContract, AuthContractArgs::__constructor(&admin));
+    let counter_contract_id = env.register(
+        CrossCounterContract,
+        CrossCounterContractArgs::__constructor(&auth_contract_id, &Durability::Instance),
+    );
+    let account_id = env.register(
+        SmartCounterAccount,
+        SmartCounterAccountArgs::__constructor(&counter_contract_id, &50_u64),
+    );
+
+    let auth_client = AuthContractClient::new(&env, &auth_contract_id);
+    auth_client.grant_role(&symbol_short!("COUNTER"), &account_id);
+
+    let counter_client = CrossCounterContractClient::new(&env, &counter_contract_id);
+
+    // Drive `add` through the real require_counter -> require_auth ->
+    // __check_auth pass-through by mocking only the account's signature,
+    // not its authorization logic.
+    env.mock_auths(&[MockAuth {
+        address: &account_id,
+        invoke: &MockAuthInvoke {
+            contract: &counter_contract_id,
+            fn_name: "add",
+            args: (account_id.clone(), 100_u64).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(counter_client.try_add(&account_id, &100_u64).is_err());
+
+    env.mock_auths(&[MockAuth {
+        address: &account_id,
+        invoke: &MockAuthInvoke {
+            contract: &counter_contract_id,
+            fn_name: "add",
+            args: (account_id.clone(), 10_u64).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert_eq!(counter_client.try_add(&account_id, &10_u64), Ok(Ok(10_u64)));
+}
+
+#[test]
+fn test_smart_account_rejects_over_threshold_subtract_end_to_end() {
+    let env = Env::default();
+    let admin = <soroban_sdk::Address as Address>::generate(&env);
+
+    env.mock_all_auths();
+
+    let auth_contract_id = env.register(AuthContract, AuthContractArgs::__constructor(&admin));
+    let counter_contract_id = env.register(
+        CrossCounterContract,
+        CrossCounterContractArgs::__constructor(&auth_contract_id, &Durability::Instance),
+    );
+    let account_id = env.register(
+        SmartCounterAccount,
+        SmartCounterAccountArgs::__constructor(&counter_contract_id, &50_u64),
+    );
+
+    let counter_client = CrossCounterContractClient::new(&env, &counter_contract_id);
+
+    // subtract needs no COUNTER role, but it's still gated by the account's
+    // threshold policy through the same __check_auth pass-through.
+    env.mock_auths(&[MockAuth {
+        address: &account_id,
+        invoke: &MockAuthInvoke {
+            contract: &counter_contract_id,
+            fn_name: "subtract",
+            args: (account_id.clone(), 100_u64).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(counter_client.try_subtract(&account_id, &100_u64).is_err());
+
+    env.mock_auths(&[MockAuth {
+        address: &account_id,
+        invoke: &MockAuthInvoke {
+            contract: &counter_contract_id,
+            fn_name: "subtract",
+            args: (account_id.clone(), 10_u64).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert_eq!(counter_client.try_subtract(&account_id, &10_u64), Ok(Ok(0_u64)));
+}
+
+#[test]
+fn test_durability_modes_extend_ttl() {
+    for durability in [
+        Durability::Instance,
+        Durability::Persistent,
+        Durability::Temporary,
+    ] {
+        let env = Env::default();
+        let admin = <soroban_sdk::Address as Address>::generate(&env);
+        env.mock_all_auths();
+
+        let auth_contract_id = env.register(AuthContract, AuthContractArgs::__constructor(&admin));
+        let counter_contract_id = env.register(
+            CrossCounterContract,
+            CrossCounterContractArgs::__constructor(&auth_contract_id, &durability),
+        );
+        let auth_client = AuthContractClient::new(&env, &auth_contract_id);
+        let counter_client = CrossCounterContractClient::new(&env, &counter_contract_id);
+
+        let user_a = <soroban_sdk::Address as Address>::generate(&env);
+        auth_client.set_role(&symbol_short!("COUNTER"), &user_a);
+
+        let ttl = || {
+            env.as_contract(&counter_contract_id, || match durability {
+                Durability::Instance => env.storage().instance().get_ttl(),
+                Durability::Persistent => env
+                    .storage()
+                    .persistent()
+                    .get_ttl(&crate::cross_contract::DataKey::Count),
+                Durability::Temporary => env
+                    .storage()
+                    .temporary()
+                    .get_ttl(&crate::cross_contract::DataKey::Count),
+            })
+        };
+
+        // Let the TTL decay to 1 ledger so only add() can have bumped it.
+        let ttl_after_construct = ttl();
+        env.ledger()
+            .with_mut(|li| li.sequence_number += ttl_after_construct - 1);
+
+        counter_client.add(&user_a, &1_u64);
+
+        assert!(ttl() > 1);
+    }
+}
+
+#[test]
+fn test_count_extends_ttl_on_read_only_access() {
+    let env = Env::default();
+    let admin = <soroban_sdk::Address as Address>::generate(&env);
+    env.mock_all_auths();
+
+    let auth_contract_id = env.register(AuthContract, AuthContractArgs::__constructor(&admin));
+    let counter_contract_id = env.register(
+        CrossCounterContract,
+        CrossCounterContractArgs::__constructor(&auth_contract_id, &Durability::Persistent),
+    );
+    let counter_client = CrossCounterContractClient::new(&env, &counter_contract_id);
+
+    let ttl_after_construct = env.as_contract(&counter_contract_id, || {
+        env.storage()
+            .persistent()
+            .get_ttl(&crate::cross_contract::DataKey::Count)
+    });
+
+    // Let the TTL decay, then read the count without ever calling add/subtract.
+    env.ledger()
+        .with_mut(|li| li.sequence_number += ttl_after_construct - 1);
+    counter_client.count();
+
+    let ttl_after_read = env.as_contract(&counter_contract_id, || {
+        env.storage()
+            .persistent()
+            .get_ttl(&crate::cross_contract::DataKey::Count)
+    });
+
+    assert!(ttl_after_read > 1);
+}
+
+#[test]
+fn test_address_holds_multiple_roles() {
+    let env = Env::default();
+    let admin = <soroban_sdk::Address as Address>::generate(&env);
+
+    env.mock_all_auths();
+
+    let auth_contract_id = env.register(AuthContract, AuthContractArgs::__constructor(&admin));
+    let counter_contract_id = env.register(
+        CrossCounterContract,
+        CrossCounterContractArgs::__constructor(&auth_contract_id, &Durability::Instance),
+    );
+    let auth_client = AuthContractClient::new(&env, &auth_contract_id);
+    let counter_client = CrossCounterContractClient::new(&env, &counter_contract_id);
+
+    let user_a = <soroban_sdk::Address as Address>::generate(&env);
+    auth_client.grant_role(&symbol_short!("COUNTER"), &user_a);
+    auth_client.grant_role(&symbol_short!("REMOVER"), &user_a);
+
+    assert!(auth_client.has_role(&user_a, &symbol_short!("COUNTER")));
+    assert!(auth_client.has_role(&user_a, &symbol_short!("REMOVER")));
+
+    // Revoking REMOVER shouldn't affect the COUNTER role.
+    auth_client.revoke_role(&symbol_short!("REMOVER"), &user_a);
+
+    assert!(auth_client.has_role(&user_a, &symbol_short!("COUNTER")));
+    assert!(!auth_client.has_role(&user_a, &symbol_short!("REMOVER")));
+
+    assert_eq!(1_u64, counter_client.add(&user_a, &1_u64));
+}